@@ -0,0 +1,206 @@
+use crate::CouldBeSliceOf;
+
+/// A persistent, navigable window into a `source` slice.
+///
+/// Where [`ResizeSlice`](crate::ResizeSlice) and [`CouldBeSliceOf`] perform one-shot
+/// adjustments and return a fresh slice each time, `SliceCursor` keeps its `start..end` window
+/// alongside `source` and lets you grow, shrink or slide that window through a chain of
+/// builder-style calls, maintaining `start <= end <= source.len()` as an invariant throughout.
+/// This makes it useful for things like an editor selection or a tokenizer's lookahead.
+///
+/// # Examples
+/// ```
+/// # use resize_slice2::Cursor;
+/// let source = &[1, 2, 3, 4, 5];
+/// let cursor = source.cursor().shrink_left(1).shrink_right(1);
+/// assert_eq!(cursor.get(), &[2, 3, 4]);
+///
+/// let cursor = cursor.grow_left(1).slide(1);
+/// assert_eq!(cursor.get(), &[2, 3, 4, 5]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceCursor<'source, T> {
+    source: &'source [T],
+    start: usize,
+    end: usize,
+}
+
+impl<'source, T> SliceCursor<'source, T> {
+    /// Creates a cursor over the whole of `source`.
+    pub fn new(source: &'source [T]) -> Self {
+        Self {
+            source,
+            start: 0,
+            end: source.len(),
+        }
+    }
+
+    /// Creates a cursor windowed to `slice`'s position within `source`, or `None` if `slice` is
+    /// not a slice of `source`.
+    pub fn new_at(slice: &[T], source: &'source [T]) -> Option<Self> {
+        let range = slice.slice_range(source)?;
+        Some(Self {
+            source,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    /// Materializes the cursor's current window.
+    pub fn get(&self) -> &'source [T] {
+        &self.source[self.start..self.end]
+    }
+
+    /// Grows the window to the left by up to `n` elements, clamped to the start of `source`.
+    pub fn grow_left(mut self, n: usize) -> Self {
+        self.start = self.start.saturating_sub(n);
+        self
+    }
+
+    /// Grows the window to the right by up to `n` elements, clamped to the end of `source`.
+    pub fn grow_right(mut self, n: usize) -> Self {
+        self.end = self.end.saturating_add(n).min(self.source.len());
+        self
+    }
+
+    /// Shrinks the window from the left by up to `n` elements, never moving past the window's end.
+    pub fn shrink_left(mut self, n: usize) -> Self {
+        self.start = self.start.saturating_add(n).min(self.end);
+        self
+    }
+
+    /// Shrinks the window from the right by up to `n` elements, never moving past the window's start.
+    pub fn shrink_right(mut self, n: usize) -> Self {
+        self.end = self.end.saturating_sub(n).max(self.start);
+        self
+    }
+
+    /// Slides the window by `n` elements, preserving its length. Positive values move toward the
+    /// end of `source`, negative values toward the start; both are clamped so the window stays
+    /// within `source`.
+    pub fn slide(mut self, n: isize) -> Self {
+        let len = self.end - self.start;
+
+        let new_start = if n >= 0 {
+            self.start
+                .saturating_add(n as usize)
+                .min(self.source.len() - len)
+        } else {
+            self.start.saturating_sub(n.unsigned_abs())
+        };
+
+        self.start = new_start;
+        self.end = new_start + len;
+        self
+    }
+
+    /// Moves the window's start to the start of `source`.
+    pub fn to_start(mut self) -> Self {
+        self.start = 0;
+        self
+    }
+
+    /// Moves the window's end to the end of `source`.
+    pub fn to_end(mut self) -> Self {
+        self.end = self.source.len();
+        self
+    }
+}
+
+/// Creates a [`SliceCursor`] over a slice.
+pub trait Cursor<'source, T> {
+    /// Creates a cursor over the whole of `self`.
+    fn cursor(self) -> SliceCursor<'source, T>;
+
+    /// Creates a cursor windowed to `self`'s position within `source`, or `None` if `self` is
+    /// not a slice of `source`.
+    fn cursor_in(self, source: &'source [T]) -> Option<SliceCursor<'source, T>>;
+}
+
+impl<'source, T> Cursor<'source, T> for &'source [T] {
+    fn cursor(self) -> SliceCursor<'source, T> {
+        SliceCursor::new(self)
+    }
+
+    fn cursor_in(self, source: &'source [T]) -> Option<SliceCursor<'source, T>> {
+        SliceCursor::new_at(self, source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_cursor_starts_over_whole_source() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor();
+        assert_eq!(cursor.get(), &source);
+    }
+
+    #[test]
+    fn test_cursor_in_windowed() {
+        let source = [1, 2, 3, 4, 5];
+        let slice = &source[1..3];
+        let cursor = slice.cursor_in(&source).unwrap();
+        assert_eq!(cursor.get(), &[2, 3]);
+    }
+
+    #[test]
+    fn test_cursor_in_not_contained() {
+        let source = [1, 2, 3, 4, 5];
+        let other = [6, 7, 8];
+        assert_eq!(other.cursor_in(&source), None);
+    }
+
+    #[test]
+    fn test_grow_and_shrink() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().shrink_left(1).shrink_right(1);
+        assert_eq!(cursor.get(), &[2, 3, 4]);
+
+        let cursor = cursor.grow_left(1).grow_right(1);
+        assert_eq!(cursor.get(), &source);
+    }
+
+    #[test]
+    fn test_grow_clamps_to_source_bounds() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().grow_left(10).grow_right(10);
+        assert_eq!(cursor.get(), &source);
+    }
+
+    #[test]
+    fn test_shrink_clamps_to_empty() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().shrink_left(10);
+        assert_eq!(cursor.get(), &[] as &[i32]);
+    }
+
+    #[test]
+    fn test_slide() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().shrink_right(3).slide(2);
+        assert_eq!(cursor.get(), &[3, 4]);
+    }
+
+    #[test]
+    fn test_slide_clamps_to_source_bounds() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().shrink_right(3).slide(10);
+        assert_eq!(cursor.get(), &[4, 5]);
+
+        let cursor = cursor.slide(-10);
+        assert_eq!(cursor.get(), &[1, 2]);
+    }
+
+    #[test]
+    fn test_to_start_and_to_end() {
+        let source = [1, 2, 3, 4, 5];
+        let cursor = source.cursor().shrink_left(2).shrink_right(2);
+        assert_eq!(cursor.get(), &[3]);
+
+        assert_eq!(cursor.to_start().get(), &[1, 2, 3]);
+        assert_eq!(cursor.to_end().get(), &[3, 4, 5]);
+    }
+}
@@ -16,6 +16,43 @@ use std::ops::Range;
 pub trait CouldBeSliceOf<T> {
     /// Returns `true` if `self` could be a slice of `source`.
     fn is_slice_of(&self, source: &[T]) -> bool;
+
+    /// Returns the index range `self` occupies within `source`, or `None` if
+    /// `self` is not a slice of `source`.
+    ///
+    /// This is the equivalent of the standard library's (nightly-only)
+    /// `slice::subslice_range`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use resize_slice2::CouldBeSliceOf;
+    /// let source = &[1, 2, 3, 4, 5];
+    /// let slice = &source[1..3];
+    ///
+    /// assert_eq!(slice.slice_range(source), Some(1..3));
+    ///
+    /// let b = &[6, 7, 8];
+    /// assert_eq!(source.slice_range(b), None);
+    /// ```
+    fn slice_range(&self, source: &[T]) -> Option<Range<usize>>;
+
+    /// Returns the parts of `source` that flank `self`, as `(prefix, suffix)`, or `None` if
+    /// `self` is not a slice of `source`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use resize_slice2::CouldBeSliceOf;
+    /// let source = &[1, 2, 3, 4, 5];
+    /// let slice = &source[1..3];
+    ///
+    /// let (prefix, suffix) = slice.split_around(source).unwrap();
+    /// assert_eq!(prefix, &[1]);
+    /// assert_eq!(suffix, &[4, 5]);
+    /// ```
+    fn split_around<'source>(&self, source: &'source [T]) -> Option<(&'source [T], &'source [T])> {
+        let range = self.slice_range(source)?;
+        Some((&source[..range.start], &source[range.end..]))
+    }
 }
 
 impl<T> CouldBeSliceOf<T> for &[T] {
@@ -32,6 +69,19 @@ impl<T> CouldBeSliceOf<T> for &[T] {
 
         outer_start as usize <= inner_start as usize && inner_end as usize <= outer_end as usize
     }
+
+    fn slice_range(&self, source: &[T]) -> Option<Range<usize>> {
+        if !self.is_slice_of(source) {
+            return None;
+        }
+
+        let start = (self.as_ptr() as usize - source.as_ptr() as usize)
+            .checked_div(std::mem::size_of::<T>())
+            .unwrap_or(0);
+        let end = start + self.len();
+
+        Some(start..end)
+    }
 }
 
 impl<T, const N: usize> CouldBeSliceOf<T> for &[T; N] {
@@ -48,6 +98,19 @@ impl<T, const N: usize> CouldBeSliceOf<T> for &[T; N] {
 
         outer_start as usize <= inner_start as usize && inner_end as usize <= outer_end as usize
     }
+
+    fn slice_range(&self, source: &[T]) -> Option<Range<usize>> {
+        if !self.is_slice_of(source) {
+            return None;
+        }
+
+        let start = (self.as_ptr() as usize - source.as_ptr() as usize)
+            .checked_div(std::mem::size_of::<T>())
+            .unwrap_or(0);
+        let end = start + self.len();
+
+        Some(start..end)
+    }
 }
 
 #[cfg(test)]
@@ -118,4 +181,30 @@ mod test {
         assert!(b.is_slice_of(a));
         assert!(a.is_slice_of(b));
     }
+
+    #[test]
+    fn split_around_middle() {
+        let a = &SOURCE;
+        let b = &a[3..6];
+
+        let (prefix, suffix) = b.split_around(a).unwrap();
+        assert_eq!(prefix, &SOURCE[..3]);
+        assert_eq!(suffix, &SOURCE[6..]);
+    }
+
+    #[test]
+    fn split_around_not_contained() {
+        let a = &SOURCE[..5];
+        let b = &SOURCE[5..];
+
+        assert_eq!(b.split_around(a), None);
+    }
+
+    #[test]
+    fn slice_range_zero_sized_type() {
+        let source: &[(); 5] = &[(), (), (), (), ()];
+        let slice = &source[1..3];
+
+        assert_eq!(slice.slice_range(source), Some(0..2));
+    }
 }
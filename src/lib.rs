@@ -39,11 +39,19 @@
 //! ```
 
 use num_traits::AsPrimitive;
-use std::ops::{Add, Range, RangeFrom, RangeFull, RangeTo};
+use std::ops::{
+    Add, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
 
 mod is_slice;
 pub use is_slice::CouldBeSliceOf;
 
+mod resize_mut;
+pub use resize_mut::{ResizeSliceMut, SliceCursorMut};
+
+mod cursor;
+pub use cursor::{Cursor, SliceCursor};
+
 #[cfg(test)]
 mod test;
 
@@ -134,6 +142,15 @@ pub trait ResizeSlice<'a, 'source: 'a, T, R, E> {
     /// result:           |-------|
     /// ```
     fn try_resize(&'a self, source: &'source [T], by: R) -> Result<&'source [T], E>;
+
+    /// Resizes the slice using the given range `by`, like [`resize`](Self::resize), but never
+    /// fails: offsets that would land outside of `source` are clamped to `0..=source.len()`
+    /// instead of panicking, and if the clamped end would land before the clamped start, an
+    /// empty slice at the clamped start is returned instead.
+    ///
+    /// Useful for scroll/pan-style adjustments where "grow or shrink as far as possible" is the
+    /// desired behavior, rather than an error.
+    fn resize_saturating(&'a self, source: &'source [T], by: R) -> &'source [T];
 }
 
 impl<'a, 'source: 'a, T> ResizeSlice<'a, 'source, T, RangeFull, Error> for &'a [T] {
@@ -149,6 +166,11 @@ impl<'a, 'source: 'a, T> ResizeSlice<'a, 'source, T, RangeFull, Error> for &'a [
             Err(Error::NotInSource)
         }
     }
+
+    #[inline(always)]
+    fn resize_saturating(&'a self, source: &'source [T], _by: RangeFull) -> &'source [T] {
+        source
+    }
 }
 
 impl<'a, 'source: 'a, T, I> ResizeSlice<'a, 'source, T, RangeFrom<I>, Error> for &'a [T]
@@ -157,20 +179,17 @@ where
     usize: AsPrimitive<I>,
 {
     fn resize(&'a self, source: &'source [T], by: RangeFrom<I>) -> &'source [T] {
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
+        let self_start = self.slice_range(source).expect("self is not a slice of source").start;
         let new_start = self_start.as_() + by.start;
 
         &source[new_start.as_()..]
     }
 
     fn try_resize(&'a self, source: &'source [T], by: RangeFrom<I>) -> Result<&'source [T], Error> {
-        if !self.is_slice_of(source) {
+        let Some(self_range) = self.slice_range(source) else {
             return Err(Error::NotInSource);
-        }
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
-        let new_start = self_start.as_() + by.start;
+        };
+        let new_start = self_range.start.as_() + by.start;
 
         if new_start < 0usize.as_() || new_start > source.len().as_() {
             return Err(Error::OutOfBounds);
@@ -178,6 +197,21 @@ where
 
         Ok(&source[new_start.as_()..])
     }
+
+    fn resize_saturating(&'a self, source: &'source [T], by: RangeFrom<I>) -> &'source [T] {
+        let self_start = self.slice_range(source).map(|r| r.start).unwrap_or(0);
+        let new_start = self_start.as_() + by.start;
+
+        let clamped_start = if new_start < 0usize.as_() {
+            0
+        } else if new_start > source.len().as_() {
+            source.len()
+        } else {
+            new_start.as_()
+        };
+
+        &source[clamped_start..]
+    }
 }
 
 impl<'a, 'source: 'a, T, I> ResizeSlice<'a, 'source, T, RangeTo<I>, Error> for &'source [T]
@@ -186,22 +220,17 @@ where
     usize: AsPrimitive<I>,
 {
     fn resize(&'a self, source: &'source [T], by: RangeTo<I>) -> &'source [T] {
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
-        let self_end = self_start + self.len();
+        let self_end = self.slice_range(source).expect("self is not a slice of source").end;
         let new_end = self_end.as_() + by.end;
 
         &source[..new_end.as_()]
     }
 
     fn try_resize(&'a self, source: &'source [T], by: RangeTo<I>) -> Result<&'source [T], Error> {
-        if !self.is_slice_of(source) {
+        let Some(self_range) = self.slice_range(source) else {
             return Err(Error::NotInSource);
-        }
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
-        let self_end = self_start + self.len();
-        let new_end = self_end.as_() + by.end;
+        };
+        let new_end = self_range.end.as_() + by.end;
 
         if new_end < 0usize.as_() || new_end > source.len().as_() {
             return Err(Error::OutOfBounds);
@@ -209,6 +238,21 @@ where
 
         Ok(&source[..new_end.as_()])
     }
+
+    fn resize_saturating(&'a self, source: &'source [T], by: RangeTo<I>) -> &'source [T] {
+        let self_end = self.slice_range(source).map(|r| r.end).unwrap_or(0);
+        let new_end = self_end.as_() + by.end;
+
+        let clamped_end = if new_end < 0usize.as_() {
+            0
+        } else if new_end > source.len().as_() {
+            source.len()
+        } else {
+            new_end.as_()
+        };
+
+        &source[..clamped_end]
+    }
 }
 
 impl<'a, 'source: 'a, T, I> ResizeSlice<'a, 'source, T, Range<I>, Error> for &'a [T]
@@ -217,21 +261,19 @@ where
     usize: AsPrimitive<I>,
 {
     fn resize(&'a self, source: &'source [T], by: Range<I>) -> &'source [T] {
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
-        let self_end = self_start + self.len();
-        let new_start = self_start.as_() + by.start;
-        let new_end = self_end.as_() + by.end;
+        let self_range = self.slice_range(source).expect("self is not a slice of source");
+        let new_start = self_range.start.as_() + by.start;
+        let new_end = self_range.end.as_() + by.end;
 
         &source[new_start.as_()..new_end.as_()]
     }
 
     fn try_resize(&'a self, source: &'source [T], by: Range<I>) -> Result<&'source [T], Error> {
-        let self_start =
-            (self.as_ptr() as usize - source.as_ptr() as usize) / std::mem::size_of::<T>();
-        let self_end = self_start + self.len();
-        let new_start = self_start.as_() + by.start;
-        let new_end = self_end.as_() + by.end;
+        let Some(self_range) = self.slice_range(source) else {
+            return Err(Error::NotInSource);
+        };
+        let new_start = self_range.start.as_() + by.start;
+        let new_end = self_range.end.as_() + by.end;
 
         if new_end < new_start {
             return Err(Error::NegativeSlice);
@@ -241,4 +283,139 @@ where
 
         Ok(&source[new_start.as_()..new_end.as_()])
     }
+
+    fn resize_saturating(&'a self, source: &'source [T], by: Range<I>) -> &'source [T] {
+        let self_range = self.slice_range(source).unwrap_or(0..0);
+        let new_start = self_range.start.as_() + by.start;
+        let new_end = self_range.end.as_() + by.end;
+
+        let clamped_start = if new_start < 0usize.as_() {
+            0
+        } else if new_start > source.len().as_() {
+            source.len()
+        } else {
+            new_start.as_()
+        };
+
+        let clamped_end = if new_end < 0usize.as_() {
+            0
+        } else if new_end > source.len().as_() {
+            source.len()
+        } else {
+            new_end.as_()
+        };
+
+        if clamped_end < clamped_start {
+            &source[clamped_start..clamped_start]
+        } else {
+            &source[clamped_start..clamped_end]
+        }
+    }
+}
+
+impl<'a, 'source: 'a, T, I> ResizeSlice<'a, 'source, T, RangeInclusive<I>, Error> for &'a [T]
+where
+    I: AsPrimitive<usize> + Copy + Add<Output = I> + PartialOrd,
+    usize: AsPrimitive<I>,
+{
+    fn resize(&'a self, source: &'source [T], by: RangeInclusive<I>) -> &'source [T] {
+        let self_range = self.slice_range(source).expect("self is not a slice of source");
+        let new_start = self_range.start.as_() + *by.start();
+        let new_end = self_range.end.as_() + *by.end();
+
+        &source[new_start.as_()..new_end.as_() + 1]
+    }
+
+    fn try_resize(
+        &'a self,
+        source: &'source [T],
+        by: RangeInclusive<I>,
+    ) -> Result<&'source [T], Error> {
+        let Some(self_range) = self.slice_range(source) else {
+            return Err(Error::NotInSource);
+        };
+        let new_start = self_range.start.as_() + *by.start();
+        let new_end = self_range.end.as_() + *by.end();
+
+        if new_end < new_start {
+            return Err(Error::NegativeSlice);
+        } else if new_start < 0usize.as_() || new_end >= source.len().as_() {
+            return Err(Error::OutOfBounds);
+        }
+
+        Ok(&source[new_start.as_()..new_end.as_() + 1])
+    }
+
+    fn resize_saturating(&'a self, source: &'source [T], by: RangeInclusive<I>) -> &'source [T] {
+        let self_range = self.slice_range(source).unwrap_or(0..0);
+        let new_start = self_range.start.as_() + *by.start();
+        let new_end = self_range.end.as_() + *by.end();
+
+        let clamped_start = if new_start < 0usize.as_() {
+            0
+        } else if new_start > source.len().as_() {
+            source.len()
+        } else {
+            new_start.as_()
+        };
+
+        let clamped_end = if new_end < 0usize.as_() {
+            0
+        } else if new_end >= source.len().as_() {
+            source.len()
+        } else {
+            new_end.as_() + 1
+        };
+
+        if clamped_end < clamped_start {
+            &source[clamped_start..clamped_start]
+        } else {
+            &source[clamped_start..clamped_end]
+        }
+    }
+}
+
+impl<'a, 'source: 'a, T, I> ResizeSlice<'a, 'source, T, RangeToInclusive<I>, Error> for &'source [T]
+where
+    I: AsPrimitive<usize> + Copy + Add<Output = I> + PartialOrd,
+    usize: AsPrimitive<I>,
+{
+    fn resize(&'a self, source: &'source [T], by: RangeToInclusive<I>) -> &'source [T] {
+        let self_end = self.slice_range(source).expect("self is not a slice of source").end;
+        let new_end = self_end.as_() + by.end;
+
+        &source[..new_end.as_() + 1]
+    }
+
+    fn try_resize(
+        &'a self,
+        source: &'source [T],
+        by: RangeToInclusive<I>,
+    ) -> Result<&'source [T], Error> {
+        let Some(self_range) = self.slice_range(source) else {
+            return Err(Error::NotInSource);
+        };
+        let new_end = self_range.end.as_() + by.end;
+
+        if new_end < 0usize.as_() || new_end >= source.len().as_() {
+            return Err(Error::OutOfBounds);
+        }
+
+        Ok(&source[..new_end.as_() + 1])
+    }
+
+    fn resize_saturating(&'a self, source: &'source [T], by: RangeToInclusive<I>) -> &'source [T] {
+        let self_end = self.slice_range(source).map(|r| r.end).unwrap_or(0);
+        let new_end = self_end.as_() + by.end;
+
+        let clamped_end = if new_end < 0usize.as_() {
+            0
+        } else if new_end >= source.len().as_() {
+            source.len()
+        } else {
+            new_end.as_() + 1
+        };
+
+        &source[..clamped_end]
+    }
 }
@@ -0,0 +1,194 @@
+use num_traits::AsPrimitive;
+use std::ops::{Add, Range, RangeFrom, RangeFull, RangeTo};
+
+use crate::Error;
+
+/// An owned, growable window into a mutable slice.
+///
+/// `&mut [T]` can't implement [`ResizeSlice`](crate::ResizeSlice) directly: growing a mutable
+/// window back into its source would require two simultaneously live `&mut` references to the
+/// same allocation, which is unsound no matter how the pointers are constructed -- there is no
+/// safe way to call such a trait. `ResizeSliceMut` sidesteps the problem the same way
+/// [`SliceCursor`](crate::SliceCursor) does for the shared case: it owns the single
+/// `&'source mut [T]` alongside the window's `start..end` and materializes the window on demand
+/// via a reborrow, so exactly one mutable reference to `source` is ever alive.
+pub struct SliceCursorMut<'source, T> {
+    source: &'source mut [T],
+    start: usize,
+    end: usize,
+}
+
+impl<'source, T> SliceCursorMut<'source, T> {
+    /// Creates a window over the whole of `source`.
+    pub fn new(source: &'source mut [T]) -> Self {
+        let end = source.len();
+        Self {
+            source,
+            start: 0,
+            end,
+        }
+    }
+
+    /// Materializes the window as a mutable slice.
+    pub fn get_mut(&mut self) -> &mut [T] {
+        &mut self.source[self.start..self.end]
+    }
+
+    /// Materializes the window as a shared slice.
+    pub fn get(&self) -> &[T] {
+        &self.source[self.start..self.end]
+    }
+
+    /// Resizes the window using the given range `by`. May panic if the new window would be out
+    /// of bounds of `source`.
+    ///
+    /// The start of the range is relative to the start of the window.
+    /// The end of the range is relative to the end of the window.
+    pub fn resize<R>(self, by: R) -> Self
+    where
+        Self: ResizeSliceMut<R, Error>,
+    {
+        ResizeSliceMut::resize(self, by)
+    }
+
+    /// Resizes the window using the given range `by`.
+    ///
+    /// The start of the range is relative to the start of the window.
+    /// The end of the range is relative to the end of the window.
+    pub fn try_resize<R>(self, by: R) -> Result<Self, Error>
+    where
+        Self: ResizeSliceMut<R, Error>,
+    {
+        ResizeSliceMut::try_resize(self, by)
+    }
+}
+
+/// Resizes a [`SliceCursorMut`] using a range `R`, relative to the window's current bounds.
+///
+/// This is implemented once per range type, the same way [`ResizeSlice`](crate::ResizeSlice) is.
+pub trait ResizeSliceMut<R, E>: Sized {
+    /// Resizes the window using the given range `by`. May panic if the new window would be out
+    /// of bounds of `source`.
+    fn resize(self, by: R) -> Self;
+
+    /// Resizes the window using the given range `by`.
+    fn try_resize(self, by: R) -> Result<Self, E>;
+}
+
+impl<'source, T> ResizeSliceMut<RangeFull, Error> for SliceCursorMut<'source, T> {
+    #[inline(always)]
+    fn resize(mut self, _by: RangeFull) -> Self {
+        self.start = 0;
+        self.end = self.source.len();
+        self
+    }
+
+    #[inline(always)]
+    fn try_resize(self, by: RangeFull) -> Result<Self, Error> {
+        Ok(self.resize(by))
+    }
+}
+
+impl<'source, T, I> ResizeSliceMut<RangeFrom<I>, Error> for SliceCursorMut<'source, T>
+where
+    I: AsPrimitive<usize> + Copy + Add<Output = I> + PartialOrd,
+    usize: AsPrimitive<I>,
+{
+    fn resize(mut self, by: RangeFrom<I>) -> Self {
+        let new_start = self.start.as_() + by.start;
+        self.start = new_start.as_();
+        self
+    }
+
+    fn try_resize(mut self, by: RangeFrom<I>) -> Result<Self, Error> {
+        let new_start = self.start.as_() + by.start;
+
+        if new_start < 0usize.as_() || new_start > self.source.len().as_() {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.start = new_start.as_();
+        Ok(self)
+    }
+}
+
+impl<'source, T, I> ResizeSliceMut<RangeTo<I>, Error> for SliceCursorMut<'source, T>
+where
+    I: AsPrimitive<usize> + Copy + Add<Output = I> + PartialOrd,
+    usize: AsPrimitive<I>,
+{
+    fn resize(mut self, by: RangeTo<I>) -> Self {
+        let new_end = self.end.as_() + by.end;
+        self.end = new_end.as_();
+        self
+    }
+
+    fn try_resize(mut self, by: RangeTo<I>) -> Result<Self, Error> {
+        let new_end = self.end.as_() + by.end;
+
+        if new_end < 0usize.as_() || new_end > self.source.len().as_() {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.end = new_end.as_();
+        Ok(self)
+    }
+}
+
+impl<'source, T, I> ResizeSliceMut<Range<I>, Error> for SliceCursorMut<'source, T>
+where
+    I: AsPrimitive<usize> + Copy + Add<Output = I> + PartialOrd,
+    usize: AsPrimitive<I>,
+{
+    fn resize(mut self, by: Range<I>) -> Self {
+        self.start = (self.start.as_() + by.start).as_();
+        self.end = (self.end.as_() + by.end).as_();
+        self
+    }
+
+    fn try_resize(mut self, by: Range<I>) -> Result<Self, Error> {
+        let new_start = self.start.as_() + by.start;
+        let new_end = self.end.as_() + by.end;
+
+        if new_end < new_start {
+            return Err(Error::NegativeSlice);
+        } else if new_start < 0usize.as_() || new_end > self.source.len().as_() {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.start = new_start.as_();
+        self.end = new_end.as_();
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_resize_mut_range_full() {
+        let mut source = [1, 2, 3, 4, 5];
+        let mut cursor = SliceCursorMut::new(&mut source).resize(..);
+        cursor.get_mut()[0] = 10;
+        assert_eq!(cursor.get(), &[10, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_resize_mut_range() {
+        let mut source = [1, 2, 3, 4, 5];
+        let cursor = SliceCursorMut::new(&mut source).resize(1..-2);
+        assert_eq!(cursor.get(), &[2, 3]);
+        let cursor = cursor.resize(-1..2);
+        assert_eq!(cursor.get(), &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_try_resize_mut_out_of_bounds() {
+        let mut source = [1, 2, 3, 4, 5];
+        let cursor = SliceCursorMut::new(&mut source);
+        let err = cursor.try_resize(0..10).err();
+        assert_eq!(err, Some(Error::OutOfBounds));
+    }
+}
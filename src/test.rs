@@ -51,6 +51,72 @@ fn test_not_source_slice_upper() {
     assert_eq!(extended, Err(Error::NotInSource));
 }
 
+#[test]
+fn test_resize_saturating_clamps_start_and_end() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..3];
+    let resized = slice.resize_saturating(&source, -10..10);
+    assert_eq!(resized, &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_resize_saturating_collapses_to_empty() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..3];
+    let resized = slice.resize_saturating(&source, 10..-10);
+    assert_eq!(resized, &[] as &[i32]);
+}
+
+#[test]
+fn test_resize_saturating_range_from() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..3];
+    let resized = slice.resize_saturating(&source, -10..);
+    assert_eq!(resized, &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_resize_saturating_range_to() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..3];
+    let resized = slice.resize_saturating(&source, ..10);
+    assert_eq!(resized, &[1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_resize_range_inclusive() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..2];
+    assert_eq!(slice, &[2]);
+    let extended = slice.resize(&source, 0..=1);
+    assert_eq!(extended, &[2, 3, 4]);
+}
+
+#[test]
+fn test_resize_range_inclusive_end_at_source_len() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..2];
+    let extended = slice.try_resize(&source, 0..=2).unwrap();
+    assert_eq!(extended, &[2, 3, 4, 5]);
+}
+
+#[test]
+fn test_resize_range_inclusive_out_of_bounds() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..2];
+    let err = slice.try_resize(&source, 0..=3).unwrap_err();
+    assert_eq!(err, Error::OutOfBounds);
+}
+
+#[test]
+fn test_resize_range_to_inclusive() {
+    let source = [1, 2, 3, 4, 5];
+    let slice = &source[1..2];
+    let extended = slice.resize(&source, ..=2);
+    assert_eq!(extended, &[1, 2, 3, 4, 5]);
+}
+
 #[test]
 fn extend_lifetime_compiles() {
     let source = [1, 2, 3, 4, 5];